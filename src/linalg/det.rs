@@ -0,0 +1,40 @@
+use std::ops::Neg;
+use num::traits::Num;
+use tensor::Tensor;
+use super::lu::lu;
+
+/// Computes the determinant of the square matrix `a` via LU decomposition: the product of the
+/// `U` diagonal times the permutation sign.
+pub fn det<T: Copy + Num + PartialOrd + Neg<Output=T>>(a: &Tensor<T>) -> T {
+    let decomp = lu(a);
+    let n = decomp.u.shape()[0];
+
+    let mut d = decomp.sign;
+    for i in 0..n {
+        d = d * decomp.u.get(i, i);
+    }
+    d
+}
+
+#[cfg(test)]
+mod tests {
+    use tensor::DoubleTensor;
+    use super::det;
+
+    fn approx_eq(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn det_known_value() {
+        let a = DoubleTensor::new(vec![3.0, 0.0, 0.0, 2.0]).reshaped(&[2, 2]);
+        assert!(approx_eq(det(&a), 6.0));
+    }
+
+    #[test]
+    fn det_requires_pivoting() {
+        // The (0, 0) entry is zero, so LU must swap rows to pivot; the swap flips the sign.
+        let a = DoubleTensor::new(vec![0.0, 1.0, 1.0, 0.0]).reshaped(&[2, 2]);
+        assert!(approx_eq(det(&a), -1.0));
+    }
+}
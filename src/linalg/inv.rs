@@ -0,0 +1,74 @@
+use std::ops::Neg;
+use num::traits::Num;
+use tensor::Tensor;
+use super::lu::lu;
+
+/// Computes the inverse of the square matrix `a` by solving `A x = e` for each column `e` of
+/// the identity, via forward/back substitution against the `L`/`U` factors from `lu`.
+pub fn inv<T: Copy + Num + PartialOrd + Neg<Output=T>>(a: &Tensor<T>) -> Tensor<T> {
+    let decomp = lu(a);
+    let n = a.shape()[0];
+    let mut result = Tensor::zeros(&[n, n]);
+
+    for col in 0..n {
+        // b = P * e_col: e_col permuted the same way the rows of `a` were.
+        let mut b: Vec<T> = vec![T::zero(); n];
+        for i in 0..n {
+            if decomp.permutation[i] == col {
+                b[i] = T::one();
+            }
+        }
+
+        // Forward substitution: L y = b (L has a unit diagonal).
+        let mut y: Vec<T> = vec![T::zero(); n];
+        for i in 0..n {
+            let mut s = b[i];
+            for j in 0..i {
+                s = s - decomp.l.get(i, j) * y[j];
+            }
+            y[i] = s;
+        }
+
+        // Back substitution: U x = y.
+        let mut x: Vec<T> = vec![T::zero(); n];
+        for ii in 0..n {
+            let i = n - 1 - ii;
+            let mut s = y[i];
+            for j in (i + 1)..n {
+                s = s - decomp.u.get(i, j) * x[j];
+            }
+            x[i] = s / decomp.u.get(i, i);
+        }
+
+        for i in 0..n {
+            result.set(i, col, x[i]);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use tensor::DoubleTensor;
+    use super::inv;
+
+    fn approx_eq(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn inv_round_trip_with_pivoting() {
+        // (0, 0) is zero, so LU must pivot before it can factor this matrix.
+        let a = DoubleTensor::new(vec![0.0, 2.0, 1.0, 3.0]).reshaped(&[2, 2]);
+        let a_inv = inv(&a);
+        let product = a.dot(&a_inv);
+
+        for i in 0..2 {
+            for j in 0..2 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!(approx_eq(product.get(i, j), expected));
+            }
+        }
+    }
+}
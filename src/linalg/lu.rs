@@ -0,0 +1,110 @@
+use std::ops::Neg;
+use num::traits::Num;
+use tensor::Tensor;
+use super::assert_square;
+
+/// An LU decomposition of a square matrix with partial pivoting: `P * A = L * U`.
+pub struct LU<T> {
+    /// Lower-triangular factor with a unit diagonal.
+    pub l: Tensor<T>,
+    /// Upper-triangular factor.
+    pub u: Tensor<T>,
+    /// `permutation[i]` is the row of the original matrix that ended up in row `i` of `P * A`.
+    pub permutation: Vec<usize>,
+    /// Sign of the row permutation (`T::one()` or `-T::one()`), i.e. the parity of its swaps.
+    pub sign: T,
+}
+
+fn abs_val<T: Copy + Num + PartialOrd + Neg<Output=T>>(v: T) -> T {
+    if v < T::zero() { -v } else { v }
+}
+
+/// Performs an LU decomposition with partial pivoting of the square matrix `a`. For each pivot
+/// column, the row with the largest absolute value at or below the diagonal is swapped to the
+/// diagonal (tracking the permutation and its sign), and eliminated below by storing the
+/// multipliers `a[i][k] / a[k][k]` in the lower triangle while updating the trailing submatrix.
+pub fn lu<T: Copy + Num + PartialOrd + Neg<Output=T>>(a: &Tensor<T>) -> LU<T> {
+    assert_square(a);
+    let n = a.shape()[0];
+
+    let mut m = a.clone();
+    let mut permutation: Vec<usize> = (0..n).collect();
+    let mut sign = T::one();
+
+    for k in 0..n {
+        let mut p = k;
+        let mut best = abs_val(m.get(k, k));
+        for i in (k + 1)..n {
+            let v = abs_val(m.get(i, k));
+            if v > best {
+                best = v;
+                p = i;
+            }
+        }
+
+        if p != k {
+            for j in 0..n {
+                let tmp = m.get(k, j);
+                m.set(k, j, m.get(p, j));
+                m.set(p, j, tmp);
+            }
+            permutation.swap(k, p);
+            sign = -sign;
+        }
+
+        let pivot = m.get(k, k);
+        for i in (k + 1)..n {
+            let factor = m.get(i, k) / pivot;
+            m.set(i, k, factor);
+            for j in (k + 1)..n {
+                let v = m.get(i, j) - factor * m.get(k, j);
+                m.set(i, j, v);
+            }
+        }
+    }
+
+    let mut l = Tensor::eye(n);
+    let mut u = Tensor::zeros(&[n, n]);
+    for i in 0..n {
+        for j in 0..n {
+            if j < i {
+                l.set(i, j, m.get(i, j));
+            } else {
+                u.set(i, j, m.get(i, j));
+            }
+        }
+    }
+
+    LU{l: l, u: u, permutation: permutation, sign: sign}
+}
+
+#[cfg(test)]
+mod tests {
+    use tensor::DoubleTensor;
+    use super::lu;
+
+    fn approx_eq(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn lu_reconstructs_permuted_matrix() {
+        // (0, 0) is zero, so LU must swap row 0 with row 1 to pivot.
+        let a = DoubleTensor::new(vec![0.0, 2.0, 1.0, 3.0]).reshaped(&[2, 2]);
+        let decomp = lu(&a);
+
+        // L * U should equal the rows of `a` taken in `permutation` order (i.e. P * A).
+        for i in 0..2 {
+            for j in 0..2 {
+                let mut acc = 0.0;
+                for k in 0..2 {
+                    acc += decomp.l.get(i, k) * decomp.u.get(k, j);
+                }
+                assert!(approx_eq(acc, a.get(decomp.permutation[i], j)));
+            }
+        }
+
+        assert_eq!(decomp.permutation, vec![1, 0]);
+        assert!(approx_eq(decomp.sign, -1.0));
+    }
+}
@@ -0,0 +1,17 @@
+//! Linear-algebra routines for square 2-D tensors: LU decomposition, determinant and inverse.
+
+mod lu;
+mod det;
+mod inv;
+
+pub use self::lu::{lu, LU};
+pub use self::det::det;
+pub use self::inv::inv;
+
+use num::traits::Num;
+use tensor::Tensor;
+
+fn assert_square<T: Copy + Num>(a: &Tensor<T>) {
+    assert_eq!(a.ndim(), 2, "linalg operations require a 2-D tensor");
+    assert_eq!(a.shape()[0], a.shape()[1], "linalg operations require a square matrix");
+}
@@ -0,0 +1,60 @@
+//! Conversion between tensors of different numeric element types.
+
+use num::traits::Num;
+use super::Tensor;
+
+/// A small per-element numeric conversion, implemented below for the common numeric
+/// primitives. `Tensor::cast` uses it to convert a `Tensor<T>` into a `Tensor<S>`.
+pub trait CastFrom<Src> {
+    /// Converts a single `Src` value into `Self`.
+    fn cast_from(v: Src) -> Self;
+}
+
+macro_rules! impl_cast_from {
+    ($src:ty => $($dst:ty),+) => {
+        $(
+            impl CastFrom<$src> for $dst {
+                #[inline]
+                fn cast_from(v: $src) -> $dst {
+                    v as $dst
+                }
+            }
+        )+
+    }
+}
+
+impl_cast_from!(f32   => f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_cast_from!(f64   => f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_cast_from!(i8    => f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_cast_from!(i16   => f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_cast_from!(i32   => f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_cast_from!(i64   => f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_cast_from!(isize => f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_cast_from!(u8    => f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_cast_from!(u16   => f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_cast_from!(u32   => f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_cast_from!(u64   => f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+impl_cast_from!(usize => f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl<T: Copy + Num> Tensor<T> {
+    /// Converts this tensor into a `Tensor<S>` with a different numeric element type, applying
+    /// the per-element conversion and carrying `shape` over unchanged. This is what lets
+    /// `SingleTensor`/`DoubleTensor` interoperate, and lets integer tensors built with `range`
+    /// or `filled` be promoted to float for the arithmetic in `add_floats`/`dot_floats`.
+    ///
+    /// ```
+    /// use numeric::DoubleTensor;
+    ///
+    /// let t = DoubleTensor::range(3);
+    /// let s: numeric::SingleTensor = t.cast();
+    /// ```
+    pub fn cast<S: Copy + Num + CastFrom<T>>(&self) -> Tensor<S> {
+        let data: Vec<S> = self.data.iter().map(|&v| S::cast_from(v)).collect();
+        Tensor{data: data, shape: self.shape.clone()}
+    }
+
+    /// Alias for `cast`, matching the `astype` naming used by other array libraries.
+    pub fn astype<S: Copy + Num + CastFrom<T>>(&self) -> Tensor<S> {
+        self.cast()
+    }
+}
@@ -0,0 +1,69 @@
+//! A lazy, row-major iterator over `(multi-index, value)` pairs.
+
+use num::traits::Num;
+use super::Tensor;
+
+/// Lazily walks a tensor in row-major order, yielding the per-axis index tuple alongside each
+/// element. Indices advance via an odometer in the same row-major order `unravel_index` defines,
+/// and the matching flat position is tracked as a running counter incremented once per step — it
+/// is always the next row-major position, so `next` never needs to recompute it through
+/// `ravel_index`/`strides`. This assumes `self`'s storage is densely packed in row-major order,
+/// which holds for every `Tensor` (a `TensorView` over non-contiguous borrowed storage is a
+/// separate type and is not what this iterates). This is the primitive for reductions, masked
+/// updates and neighbor-based stencils, which would otherwise need to recompute strides by hand
+/// on every access. Being lazy, it composes with the standard `Iterator` adapters rather than
+/// collecting into a `Vec` up front.
+pub struct IndexedIter<'a, T: 'a> {
+    tensor: &'a Tensor<T>,
+    indices: Vec<usize>,
+    flat: usize,
+    remaining: usize,
+}
+
+impl<T: Copy + Num> Tensor<T> {
+    /// Returns a lazy iterator over `(multi_index, value)` pairs in row-major order.
+    ///
+    /// ```
+    /// use numeric::DoubleTensor;
+    ///
+    /// let t = DoubleTensor::range(6).reshaped(&[2, 3]);
+    /// for (index, value) in t.iter_indexed() {
+    ///     println!("{:?} -> {}", index, value);
+    /// }
+    /// ```
+    pub fn iter_indexed(&self) -> IndexedIter<T> {
+        IndexedIter{tensor: self, indices: vec![0; self.ndim()], flat: 0, remaining: self.size()}
+    }
+}
+
+impl<'a, T: Copy + Num> Iterator for IndexedIter<'a, T> {
+    type Item = (Vec<usize>, T);
+
+    fn next(&mut self) -> Option<(Vec<usize>, T)> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let current = self.indices.clone();
+        let value = self.tensor.data()[self.flat];
+        self.flat += 1;
+
+        let shape = self.tensor.shape();
+        let mut axis = shape.len();
+        while axis > 0 {
+            axis -= 1;
+            self.indices[axis] += 1;
+            if self.indices[axis] < shape[axis] {
+                break;
+            }
+            self.indices[axis] = 0;
+        }
+
+        Some((current, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
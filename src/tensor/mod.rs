@@ -52,12 +52,19 @@ pub enum AxisIndex {
     NewAxis,
     /// Picks one elements of an axis. This will remove that axis from the tensor.
     Index(isize),
-    /// Specifies a half-open range. Slice(2, 5) will pick out indices 2, 3 and 4.
-    Slice(isize, isize),
+    /// Specifies a half-open range with a step. Slice(2, 5, 1) will pick out indices 2, 3 and 4.
+    /// A negative step walks the axis backwards, e.g. Slice(5, 2, -1) picks out 5, 4 and 3. Since
+    /// `end` is exclusive, there is no value of `end` that means "stop below index 0" — use
+    /// `SliceFromRev` to reverse an axis all the way down to and including index 0.
+    Slice(isize, isize, isize),
     /// Specifies the start (inclusive) and to the end.
     SliceFrom(isize),
     /// Specifies the end (exclusive) from the start.
     SliceTo(isize),
+    /// Specifies the start (inclusive) and reverses down to and including index 0.
+    /// `SliceFromRev(-1)` reverses the whole axis; this is the only way to reach index 0 with a
+    /// negative step, which `Slice`'s exclusive `end` cannot express.
+    SliceFromRev(isize),
 }
 
 mod add_floats;
@@ -70,6 +77,16 @@ mod dot_floats;
 mod eq;
 mod indexing;
 mod concat;
+mod view;
+mod slice_assign;
+mod cast;
+mod iter_indexed;
+mod take;
+
+pub use self::cast::CastFrom;
+pub use self::iter_indexed::IndexedIter;
+
+pub use self::view::{TensorView, TensorViewMut};
 
 impl<T: Copy + Num> Tensor<T> {
     /// Creates a new tensor with no elements.
@@ -243,89 +260,15 @@ impl<T: Copy + Num> Tensor<T> {
     ///
     /// let t = DoubleTensor::ones(&[2, 3, 4]);
     ///
-    /// t.slice(&[AxisIndex::Ellipsis, AxisIndex::Slice(1, 3)]); // shape [2, 3, 2]
+    /// t.slice(&[AxisIndex::Ellipsis, AxisIndex::Slice(1, 3, 1)]); // shape [2, 3, 2]
     /// t.slice(&[AxisIndex::Index(-1)]); // shape [3, 4]
     /// t.slice(&[AxisIndex::Full, AxisIndex::SliceFrom(1), AxisIndex::Index(1)]); // shape [2, 2]
     /// ```
+    ///
+    /// This builds a `TensorView` over the existing storage and materializes it; use
+    /// `slice_view` directly to avoid the copy.
     pub fn slice(&self, slices_raw: &[AxisIndex]) -> Tensor<T> {
-        let (slices, newaxes) = self.expand_slices(slices_raw);
-
-        let n = slices.len();
-        let mut ranges: Vec<(usize, usize)> = Vec::with_capacity(n);
-        let mut dims: Vec<usize> = Vec::with_capacity(n);
-        let mut indices: Vec<usize> = Vec::with_capacity(n);
-        let mut shape: Vec<isize> = Vec::with_capacity(n);
-        let mut axis = 0;
-        for _ in 0..newaxes[0] {
-            shape.push(1);
-        }
-        for s in slices {
-            let (st, en, keepdim) = match s {
-                AxisIndex::Index(i) => {
-                    let idx = self.resolve_axis(axis, i);
-                    (idx, idx + 1, false)
-                },
-                AxisIndex::Full => {
-                    (0, self.shape[axis], true)
-                },
-                AxisIndex::Slice(start, end) => {
-                    (self.resolve_axis(axis, start), self.resolve_axis(axis, end), true)
-                },
-                AxisIndex::SliceTo(end) => {
-                    (0, self.resolve_axis(axis, end), true)
-                },
-                AxisIndex::SliceFrom(start) => {
-                    (self.resolve_axis(axis, start), self.shape[axis], true)
-                },
-                AxisIndex::Ellipsis | AxisIndex::NewAxis => {
-                    // Should have been removed by expand_slices at this point
-                    unreachable!();
-                },
-            };
-
-            ranges.push((st, en));
-            indices.push(st);
-            dims.push(en - st);
-            if keepdim {
-                shape.push((en - st) as isize);
-            }
-            for _ in 0..newaxes[axis + 1] {
-                shape.push(1);
-            }
-            axis += 1;
-        }
-
-        let mut t = Tensor::zeros(&dims);
-        let strides = self.strides();
-
-        let mut index = 0;
-        for si in 0..strides.len() {
-            index += strides[si] * indices[si];
-        }
-
-        let mut base_i = 0;
-        for _ in 0..t.data.len() {
-            let mut c = strides.len() - 1;
-
-            t.data[base_i] = self.data[index];
-            index += strides[c];
-            indices[c] += strides[c];
-            while indices[c] >= ranges[c].1 {
-                if c == 0 {
-                    break;
-                }
-                // Reset
-                indices[c] = ranges[c].0;
-                index -= dims[c] * strides[c];
-                index += strides[c - 1];
-                indices[c - 1] += 1;
-                c -= 1;
-            }
-
-            base_i += 1;
-        }
-
-        t.reshaped(&shape[..])
+        self.slice_view(slices_raw).to_owned()
     }
 
     /// Swaps two axes. This returns a new Tensor, since the memory needs to be re-arranged.
@@ -425,12 +368,12 @@ impl<T: Copy + Num> Tensor<T> {
     }
 
     #[inline]
-    fn get(&self, i: usize, j: usize) -> T {
+    pub(crate) fn get(&self, i: usize, j: usize) -> T {
         self.data[i * self.shape[1] + j]
     }
 
     #[inline]
-    fn set(&mut self, i: usize, j: usize, v: T) {
+    pub(crate) fn set(&mut self, i: usize, j: usize, v: T) {
         self.data[i * self.shape[1] + j] = v;
     }
 }
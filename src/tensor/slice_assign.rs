@@ -0,0 +1,86 @@
+//! In-place writes into a sub-region of a `Tensor`, selected the same way as `slice`.
+
+use num::traits::Num;
+use super::{AxisIndex, Tensor};
+
+/// Returns `true` if `value_shape` broadcasts onto `target_shape` using the usual trailing-axis
+/// rule: aligned from the right, each pair of dimensions must be equal or the `value_shape` one
+/// must be 1.
+fn broadcasts_onto(value_shape: &[usize], target_shape: &[usize]) -> bool {
+    if value_shape.len() > target_shape.len() {
+        return false;
+    }
+    let pad = target_shape.len() - value_shape.len();
+    for k in 0..value_shape.len() {
+        let v = value_shape[k];
+        let t = target_shape[k + pad];
+        if v != t && v != 1 {
+            return false;
+        }
+    }
+    true
+}
+
+impl<T: Copy + Num> Tensor<T> {
+    /// Writes `value` into the sub-region of `self` picked out by `slices`, broadcasting
+    /// `value`'s shape onto the selected region the same way `slice` would read it out. This
+    /// gives `tensor.slice_assign(&[AxisIndex::Ellipsis, AxisIndex::Slice(1, 3, 1)], &other)` the
+    /// effect of `tensor[..., 1:3] = other`, which read-only slicing cannot express.
+    ///
+    /// ```
+    /// use numeric::{DoubleTensor, AxisIndex};
+    ///
+    /// let mut t = DoubleTensor::zeros(&[2, 3]);
+    /// let v = DoubleTensor::ones(&[2]);
+    /// t.slice_assign(&[AxisIndex::Full, AxisIndex::Index(1)], &v);
+    /// ```
+    pub fn slice_assign(&mut self, slices: &[AxisIndex], value: &Tensor<T>) {
+        let (shape, strides, offset) = self.resolve_view(slices);
+        assert!(broadcasts_onto(&value.shape, &shape),
+                "value shape does not broadcast onto the selected region");
+
+        let pad = shape.len() - value.shape.len();
+        let size = shape.iter().fold(1, |acc, &v| acc * v);
+
+        let mut indices = vec![0usize; shape.len()];
+        for _ in 0..size {
+            let mut addr = offset as isize;
+            for k in 0..shape.len() {
+                addr += (indices[k] as isize) * strides[k];
+            }
+
+            let mut vindex: Vec<usize> = Vec::with_capacity(value.shape.len());
+            for k in 0..value.shape.len() {
+                vindex.push(if value.shape[k] == 1 { 0 } else { indices[k + pad] });
+            }
+            let vaddr = value.ravel_index(&vindex);
+
+            self.data[addr as usize] = value.data[vaddr];
+
+            let mut axis = shape.len();
+            while axis > 0 {
+                axis -= 1;
+                indices[axis] += 1;
+                if indices[axis] < shape[axis] {
+                    break;
+                }
+                indices[axis] = 0;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tensor::{AxisIndex, DoubleTensor};
+
+    #[test]
+    fn slice_assign_broadcasts_value_shape() {
+        let mut t = DoubleTensor::zeros(&[2, 3]);
+        let v = DoubleTensor::new(vec![5.0, 6.0]); // shape [2], broadcasts across rows
+
+        t.slice_assign(&[AxisIndex::Full, AxisIndex::Slice(1, 3, 1)], &v);
+
+        assert_eq!(t.data(), &vec![0.0, 5.0, 6.0, 0.0, 5.0, 6.0]);
+    }
+}
@@ -0,0 +1,64 @@
+//! Fancy indexing: gather arbitrary positions along a single axis.
+
+use num::traits::Num;
+use super::Tensor;
+
+impl<T: Copy + Num> Tensor<T> {
+    /// Builds a new tensor by selecting `indices` (possibly repeated, out-of-order) along
+    /// `axis`, e.g. reordering or sampling the rows of a matrix. The result's shape equals
+    /// `self`'s except `shape[axis]`, which becomes `indices.len()`. This complements the
+    /// contiguous-range `slice` with the permutation/gather capability that range-based
+    /// `AxisIndex` cannot express.
+    ///
+    /// ```
+    /// use numeric::DoubleTensor;
+    ///
+    /// let t = DoubleTensor::range(6).reshaped(&[3, 2]);
+    /// t.take(0, &[2, 0, 0]); // selects rows 2, 0 and 0, shape [3, 2]
+    /// ```
+    pub fn take(&self, axis: usize, indices: &[usize]) -> Tensor<T> {
+        assert!(axis < self.ndim(), "axis out of bounds");
+
+        let strides = self.strides();
+        let inner_size = strides[axis];
+        let axis_block = self.shape()[axis] * inner_size;
+        let outer_count = self.size() / axis_block;
+
+        let mut out_shape = self.shape().clone();
+        out_shape[axis] = indices.len();
+        let new_axis_block = indices.len() * inner_size;
+
+        let mut data = vec![T::zero(); outer_count * new_axis_block];
+        for o in 0..outer_count {
+            for (p, &k) in indices.iter().enumerate() {
+                assert!(k < self.shape()[axis], "take index out of bounds");
+                let src = o * axis_block + k * inner_size;
+                let dst = o * new_axis_block + p * inner_size;
+                data[dst..dst + inner_size].copy_from_slice(&self.data()[src..src + inner_size]);
+            }
+        }
+
+        Tensor{data: data, shape: out_shape}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tensor::DoubleTensor;
+
+    #[test]
+    fn take_gathers_repeated_indices() {
+        let t = DoubleTensor::range(6).reshaped(&[3, 2]);
+        let gathered = t.take(0, &[2, 0, 0]);
+
+        assert_eq!(gathered.shape(), &vec![3, 2]);
+        assert_eq!(gathered.data(), &vec![4.0, 5.0, 0.0, 1.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "take index out of bounds")]
+    fn take_rejects_out_of_bounds_index() {
+        let t = DoubleTensor::range(6).reshaped(&[3, 2]);
+        t.take(0, &[5]);
+    }
+}
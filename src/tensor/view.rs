@@ -0,0 +1,293 @@
+//! Non-copying strided views over `Tensor` storage.
+//!
+//! A `TensorView` borrows the elements of a `Tensor` and describes how to walk them with an
+//! arbitrary (possibly negative) stride per axis, so that slicing, transposing and swapping axes
+//! (`slice_view`, `transpose_view` and `swapaxes_view`) are O(1) metadata operations over shared
+//! storage instead of copying data. `slice`, `transpose` and `swapaxes` still return owned,
+//! contiguous copies.
+//! Elements are addressed as `offset + Σ index[k] * strides[k]`.
+
+use num::traits::Num;
+use super::{AxisIndex, Tensor};
+
+/// Computes the signed element displacement for moving `n` steps along an axis with the given
+/// `stride`, which may be negative.
+#[inline]
+fn stride_offset(n: usize, stride: isize) -> isize {
+    (n as isize) * stride
+}
+
+/// Returns `true` if walking `shape`/`strides` would let two distinct index tuples address the
+/// same element (e.g. a zero or repeated stride from broadcasting). Sorts axes by stride
+/// magnitude and checks that each one's stride clears the span covered by the narrower axes.
+pub(crate) fn has_aliasing(shape: &[usize], strides: &[isize]) -> bool {
+    let mut axes: Vec<usize> = (0..shape.len()).filter(|&i| shape[i] > 1).collect();
+    axes.sort_by_key(|&i| strides[i].abs());
+
+    let mut bound: isize = 0;
+    for &i in &axes {
+        let s = strides[i].abs();
+        if s <= bound {
+            return true;
+        }
+        bound += s * (shape[i] as isize - 1);
+    }
+    false
+}
+
+/// A read-only strided view into the storage of a `Tensor`. See the module docs for how
+/// elements are addressed.
+pub struct TensorView<'a, T: 'a> {
+    data: &'a [T],
+    shape: Vec<usize>,
+    strides: Vec<isize>,
+    offset: usize,
+}
+
+impl<'a, T: Copy + Num> TensorView<'a, T> {
+    /// Returns the shape of the view.
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Returns the strides (in elements, may be negative) of the view.
+    pub fn strides(&self) -> &[isize] {
+        &self.strides
+    }
+
+    /// Returns the number of axes.
+    #[inline]
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+
+    fn address(&self, indices: &[usize]) -> usize {
+        assert_eq!(indices.len(), self.shape.len());
+        let mut addr = self.offset as isize;
+        for k in 0..indices.len() {
+            addr += stride_offset(indices[k], self.strides[k]);
+        }
+        addr as usize
+    }
+
+    /// Returns the element at the given per-axis indices.
+    pub fn get(&self, indices: &[usize]) -> T {
+        self.data[self.address(indices)]
+    }
+
+    /// Materializes the view into a new, owned, contiguous `Tensor`, gathering elements in
+    /// row-major order through the strided layout.
+    pub fn to_owned(&self) -> Tensor<T> {
+        let size = self.shape.iter().fold(1, |acc, &v| acc * v);
+        let mut data = Vec::with_capacity(size);
+
+        let mut indices = vec![0usize; self.ndim()];
+        for _ in 0..size {
+            data.push(self.get(&indices));
+
+            let mut axis = self.ndim();
+            while axis > 0 {
+                axis -= 1;
+                indices[axis] += 1;
+                if indices[axis] < self.shape[axis] {
+                    break;
+                }
+                indices[axis] = 0;
+            }
+        }
+
+        Tensor{data: data, shape: self.shape.clone()}
+    }
+}
+
+/// A mutable strided view into the storage of a `Tensor`. Constructed only through
+/// `Tensor::slice_view_mut`, which rejects any view whose indices would alias the same element.
+pub struct TensorViewMut<'a, T: 'a> {
+    data: &'a mut [T],
+    shape: Vec<usize>,
+    strides: Vec<isize>,
+    offset: usize,
+}
+
+impl<'a, T: Copy + Num> TensorViewMut<'a, T> {
+    /// Returns the shape of the view.
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Returns the strides (in elements, may be negative) of the view.
+    pub fn strides(&self) -> &[isize] {
+        &self.strides
+    }
+
+    /// Returns the number of axes.
+    #[inline]
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+
+    fn address(&self, indices: &[usize]) -> usize {
+        assert_eq!(indices.len(), self.shape.len());
+        let mut addr = self.offset as isize;
+        for k in 0..indices.len() {
+            addr += stride_offset(indices[k], self.strides[k]);
+        }
+        addr as usize
+    }
+
+    /// Returns the element at the given per-axis indices.
+    pub fn get(&self, indices: &[usize]) -> T {
+        self.data[self.address(indices)]
+    }
+
+    /// Writes `value` at the given per-axis indices.
+    pub fn set(&mut self, indices: &[usize], value: T) {
+        let addr = self.address(indices);
+        self.data[addr] = value;
+    }
+}
+
+impl<T: Copy + Num> Tensor<T> {
+    /// Returns a zero-copy view over the whole tensor.
+    pub fn view(&self) -> TensorView<T> {
+        let strides = self.strides().iter().map(|&s| s as isize).collect();
+        TensorView{data: &self.data, shape: self.shape.clone(), strides: strides, offset: 0}
+    }
+
+    /// Builds a zero-copy, possibly negatively-strided view over a sub-region of the tensor,
+    /// using the same `AxisIndex` semantics as `slice`. Call `to_owned` on the result to
+    /// materialize it into a contiguous `Tensor`.
+    pub fn slice_view(&self, slices_raw: &[AxisIndex]) -> TensorView<T> {
+        let (shape, strides, offset) = self.resolve_view(slices_raw);
+        TensorView{data: &self.data, shape: shape, strides: strides, offset: offset}
+    }
+
+    /// Builds a mutable, zero-copy view over a sub-region of the tensor. Panics if the
+    /// resulting indices could alias the same element (e.g. a broadcast stride of 0), since
+    /// writes through such a view would be ambiguous.
+    pub fn slice_view_mut(&mut self, slices_raw: &[AxisIndex]) -> TensorViewMut<T> {
+        let (shape, strides, offset) = self.resolve_view(slices_raw);
+        assert!(!has_aliasing(&shape, &strides),
+                "mutable view would alias the same element through distinct indices");
+        TensorViewMut{data: &mut self.data, shape: shape, strides: strides, offset: offset}
+    }
+
+    /// Returns a view with two axes swapped; O(1) since only the shape/strides metadata
+    /// changes, unlike `swapaxes` which copies into a new contiguous `Tensor`.
+    pub fn swapaxes_view(&self, axis1: usize, axis2: usize) -> TensorView<T> {
+        assert!(axis1 < self.ndim());
+        assert!(axis2 < self.ndim());
+        assert!(axis1 != axis2);
+
+        let mut view = self.view();
+        view.shape.swap(axis1, axis2);
+        view.strides.swap(axis1, axis2);
+        view
+    }
+
+    /// Returns a transposed view of a matrix (for now, requires it to be 2D); O(1) since only
+    /// the shape/strides metadata changes, unlike `transpose` which copies.
+    pub fn transpose_view(&self) -> TensorView<T> {
+        assert!(self.ndim() == 2, "Can only tranpose a matrix (2D Tensor)");
+        self.swapaxes_view(0, 1)
+    }
+
+    pub(super) fn resolve_view(&self, slices_raw: &[AxisIndex]) -> (Vec<usize>, Vec<isize>, usize) {
+        let (slices, newaxes) = self.expand_slices(slices_raw);
+        let base_strides = self.strides();
+
+        let mut shape: Vec<usize> = Vec::with_capacity(slices.len());
+        let mut strides: Vec<isize> = Vec::with_capacity(slices.len());
+        let mut offset: isize = 0;
+        let mut axis = 0;
+
+        for _ in 0..newaxes[0] {
+            shape.push(1);
+            strides.push(0);
+        }
+        for s in slices {
+            let stride = base_strides[axis] as isize;
+            match s {
+                AxisIndex::Index(i) => {
+                    let idx = self.resolve_axis(axis, i);
+                    offset += stride_offset(idx, stride);
+                },
+                AxisIndex::Full => {
+                    shape.push(self.shape[axis]);
+                    strides.push(stride);
+                },
+                AxisIndex::Slice(start, end, step) => {
+                    let (st, n) = self.resolve_range(axis, start, end, step);
+                    offset += stride_offset(st, stride);
+                    shape.push(n);
+                    strides.push(stride * step);
+                },
+                AxisIndex::SliceTo(end) => {
+                    let (st, n) = self.resolve_range(axis, 0, end, 1);
+                    offset += stride_offset(st, stride);
+                    shape.push(n);
+                    strides.push(stride);
+                },
+                AxisIndex::SliceFrom(start) => {
+                    let len = self.shape[axis] as isize;
+                    let (st, n) = self.resolve_range(axis, start, len, 1);
+                    offset += stride_offset(st, stride);
+                    shape.push(n);
+                    strides.push(stride);
+                },
+                AxisIndex::SliceFromRev(start) => {
+                    let st = self.resolve_axis(axis, start);
+                    offset += stride_offset(st, stride);
+                    shape.push(st + 1);
+                    strides.push(-stride);
+                },
+                AxisIndex::Ellipsis | AxisIndex::NewAxis => {
+                    // Should have been removed by expand_slices at this point
+                    unreachable!();
+                },
+            }
+            for _ in 0..newaxes[axis + 1] {
+                shape.push(1);
+                strides.push(0);
+            }
+            axis += 1;
+        }
+
+        (shape, strides, offset as usize)
+    }
+
+    /// Resolves a half-open `[start, end)` range with the given `step` (which may be negative
+    /// to walk the axis backwards) into the first index to read and the number of elements.
+    fn resolve_range(&self, axis: usize, start: isize, end: isize, step: isize) -> (usize, usize) {
+        assert!(step != 0, "slice step cannot be zero");
+        let st = self.resolve_axis(axis, start) as isize;
+        let en = self.resolve_axis(axis, end) as isize;
+
+        let n = if step > 0 {
+            if en > st { (en - st + step - 1) / step } else { 0 }
+        } else {
+            if st > en { (st - en - step - 1) / (-step) } else { 0 }
+        };
+
+        (st as usize, n as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tensor::{AxisIndex, DoubleTensor};
+
+    #[test]
+    fn slice_view_reverses_whole_axis() {
+        let t = DoubleTensor::range(4);
+        let reversed = t.slice_view(&[AxisIndex::SliceFromRev(-1)]).to_owned();
+        assert_eq!(reversed.data(), &vec![3.0, 2.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn slice_view_negative_step_partial_range() {
+        let t = DoubleTensor::range(6);
+        let v = t.slice_view(&[AxisIndex::Slice(5, 2, -1)]).to_owned();
+        assert_eq!(v.data(), &vec![5.0, 4.0, 3.0]);
+    }
+}